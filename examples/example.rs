@@ -1,7 +1,7 @@
 //! `cargo run --example example`
 extern crate async_job;
 
-use async_job::{async_trait, Job, Runner, Schedule};
+use async_job::{async_trait, Job, JobError, Runner, Schedule};
 use tokio;
 use tokio::time::Duration;
 
@@ -12,8 +12,9 @@ impl Job for ExampleJob {
     fn schedule(&self) -> Option<Schedule> {
         Some("1/5 * * * * *".parse().unwrap())
     }
-    async fn handle(&mut self) {
+    async fn handle(&mut self) -> Result<(), JobError> {
         println!("Hello, I am a cron job running at: {}", self.now());
+        Ok(())
     }
 }
 
@@ -4,8 +4,10 @@
 //! Runner will spawn new async task where it will start looping through the jobs and will run their handle
 //! method once the scheduled time is reached.
 //!
-//! If your OS has enough threads to spare each job will get its own thread to execute, if not it will be
-//! executed in the same thread as the loop but will hold the loop until the job is finished.
+//! Every due job is dispatched onto its own spawned task, so a slow job never holds up the
+//! dispatch of the others. Use [`Runner::max_concurrency`] to cap how many jobs may be
+//! in-flight at once; by default it is unbounded. Subscribe to [`Runner::events`] to
+//! observe each job's start, finish, failure and skip as a [`JobEvent`].
 //!
 //! Please look at the [**`Job trait`**](./trait.Job.html) documentation for more information.
 //!
@@ -22,8 +24,9 @@
 //!     fn schedule(&self) -> Option<Schedule> {
 //!         Some("1/5 * * * * *".parse().unwrap())
 //!     }
-//!     async fn handle(&mut self) {
+//!     async fn handle(&mut self) -> Result<(), async_job::JobError> {
 //!         println!("Hello, I am a cron job running at: {}", self.now());
+//!         Ok(())
 //!     }
 //! }
 //!
@@ -65,11 +68,14 @@ use chrono::{DateTime, Duration, Utc};
 pub use cron::Schedule;
 use lazy_static::lazy_static;
 use log::{debug, error, info};
+use std::collections::HashMap;
+use std::fmt;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc, RwLock,
 };
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::sync::{Mutex, Semaphore};
 use tokio::task::JoinHandle;
 
 lazy_static! {
@@ -80,6 +86,86 @@ lazy_static! {
     pub static ref TRACKER: RwLock<Tracker> = RwLock::new(Tracker::new());
 }
 
+/// Error returned by a job's `handle` method to signal a failed run.
+///
+/// The runner uses this to decide whether the job should be retried, see
+/// [`Job::max_retries`] and [`Job::retry_backoff`].
+#[derive(Debug)]
+pub struct JobError(pub String);
+
+impl fmt::Display for JobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for JobError {}
+
+impl From<String> for JobError {
+    fn from(message: String) -> Self {
+        JobError(message)
+    }
+}
+
+impl From<&str> for JobError {
+    fn from(message: &str) -> Self {
+        JobError(message.to_string())
+    }
+}
+
+/// A scheduling or execution event emitted by the runner for a job, for
+/// metrics, health checks or driving UI updates. Subscribe via
+/// [`Runner::events`].
+#[derive(Debug, Clone)]
+pub enum JobEvent {
+    /// The job started running.
+    Started {
+        /// index of the job within the runner
+        id: usize,
+        /// when it started
+        at: DateTime<Utc>,
+    },
+    /// The job finished running successfully.
+    Finished {
+        /// index of the job within the runner
+        id: usize,
+        /// when it finished
+        at: DateTime<Utc>,
+        /// how long the run took
+        duration: Duration,
+    },
+    /// The job returned an error, panicked, or its task was cancelled.
+    Failed {
+        /// index of the job within the runner
+        id: usize,
+        /// the error, panic payload, or cancellation reason, as a string
+        error: String,
+    },
+    /// A trigger for the job fired but no run was dispatched for it.
+    Skipped {
+        /// index of the job within the runner
+        id: usize,
+        /// why the run was not dispatched
+        reason: String,
+    },
+}
+
+/// What the runner should do when a job's schedule fires again while an
+/// earlier run of that same job is still in flight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlapPolicy {
+    /// Skip the overlapping trigger entirely.
+    Skip,
+    /// Dispatch the overlapping trigger as another run instead of skipping
+    /// it. Runs of one job still take turns, since `handle` takes
+    /// `&mut self`: the new run starts once the one in flight has returned.
+    Parallel,
+    /// Keep a single pending run queued; once the in-flight instance
+    /// finishes, dispatch the queued one immediately. Overwrites any
+    /// already-queued run rather than building up a backlog.
+    QueueLatest,
+}
+
 #[async_trait]
 /// A cron job that runs for a website.
 pub trait Job: Send + Sync {
@@ -89,27 +175,59 @@ pub trait Job: Send + Sync {
         true
     }
 
-    /// In case your job takes longer to finish and it's scheduled
-    /// to start again (while its still running), default behaviour
-    /// will skip the next run while one instance is already running.
-    /// (if your OS has enough threads, and is spawning a thread for next job)
-    ///
-    /// To override this behaviour and enable it to run in parallel
-    /// with other instances of self, return `true` on this instance.
+    /// Deprecated: implement [`Job::overlap_policy`] instead. Kept only so
+    /// that existing implementations keep compiling; its default
+    /// `overlap_policy` reads this to decide between `Skip` and `Parallel`.
+    #[deprecated(since = "0.2.0", note = "implement `overlap_policy` instead")]
     fn allow_parallel_runs(&self) -> bool {
         false
     }
 
+    /// Decide what should happen when this job's schedule fires again while
+    /// an earlier run of it is still in flight.
+    ///
+    /// Defaults to mapping the deprecated [`Job::allow_parallel_runs`] so
+    /// existing implementations keep their current behaviour unchanged:
+    /// `true` maps to `OverlapPolicy::Parallel`, `false` (the default) maps
+    /// to `OverlapPolicy::Skip`.
+    #[allow(deprecated)]
+    fn overlap_policy(&self) -> OverlapPolicy {
+        if self.allow_parallel_runs() {
+            OverlapPolicy::Parallel
+        } else {
+            OverlapPolicy::Skip
+        }
+    }
+
     /// Define the run schedule for your job
     fn schedule(&self) -> Option<Schedule>;
 
     /// This is where your jobs magic happens, define the action that
     /// will happen once the cron start running your job
     ///
-    /// If this method panics, your entire job will panic and that may
-    /// or may not make the whole runner panic. Handle your errors
-    /// properly and don't let it panic.
-    async fn handle(&mut self);
+    /// Return `Err` to signal a failed run; the runner will reschedule it
+    /// according to [`Job::max_retries`] and [`Job::retry_backoff`].
+    ///
+    /// If this method panics, the runner catches it, logs it, and carries on
+    /// scheduling every other job. Register [`Runner::on_panic`] if you want
+    /// to be notified when that happens.
+    async fn handle(&mut self) -> Result<(), JobError>;
+
+    /// Maximum number of times a failed run will be retried before the
+    /// runner gives up and logs the final error.
+    ///
+    /// Defaults to `0`, i.e. no retries.
+    fn max_retries(&self) -> u32 {
+        0
+    }
+
+    /// Base delay used to space out retries, doubled after every failed
+    /// attempt (so the Nth retry waits roughly `retry_backoff * 2^(N-1)`).
+    ///
+    /// Defaults to one second.
+    fn retry_backoff(&self) -> Duration {
+        Duration::seconds(1)
+    }
 
     /// Decide wheather or not to start running your job
     fn should_run(&self) -> bool {
@@ -137,8 +255,14 @@ pub trait Job: Send + Sync {
     }
 }
 
-/// Struct for marking jobs running
-pub struct Tracker(Vec<usize>);
+/// Struct for marking jobs running, keeping count of their consecutive
+/// failed attempts so the runner can drive retries, and holding the single
+/// queued rerun used by [`OverlapPolicy::QueueLatest`].
+pub struct Tracker {
+    running: Vec<usize>,
+    attempts: HashMap<usize, u32>,
+    pending: std::collections::HashSet<usize>,
+}
 
 impl Default for Tracker {
     fn default() -> Self {
@@ -149,34 +273,72 @@ impl Default for Tracker {
 impl Tracker {
     /// Return new instance of running
     pub fn new() -> Self {
-        Tracker(vec![])
+        Tracker {
+            running: vec![],
+            attempts: HashMap::new(),
+            pending: std::collections::HashSet::new(),
+        }
     }
 
     /// Check if id of the job is marked as running
     pub fn running(&self, id: &usize) -> bool {
-        self.0.contains(id)
+        self.running.contains(id)
     }
 
     /// Set job id as running
     pub fn start(&mut self, id: &usize) -> usize {
         if !self.running(id) {
-            self.0.push(*id);
+            self.running.push(*id);
         }
-        self.0.len()
+        self.running.len()
     }
 
     /// Unmark the job from running
     pub fn stop(&mut self, id: &usize) -> usize {
         if self.running(id) {
-            match self.0.iter().position(|&r| r == *id) {
-                Some(i) => self.0.remove(i),
+            match self.running.iter().position(|&r| r == *id) {
+                Some(i) => self.running.remove(i),
                 None => 0,
             };
         }
-        self.0.len()
+        self.running.len()
+    }
+
+    /// Record another failed attempt for the job id and return the new
+    /// consecutive-failure count.
+    pub fn record_attempt(&mut self, id: &usize) -> u32 {
+        let attempt = self.attempts.entry(*id).or_insert(0);
+        *attempt += 1;
+        *attempt
+    }
+
+    /// Number of consecutive failed attempts recorded for the job id.
+    pub fn attempts(&self, id: &usize) -> u32 {
+        self.attempts.get(id).copied().unwrap_or(0)
+    }
+
+    /// Clear the failed-attempt count for the job id, e.g. after a
+    /// successful run or once retries are exhausted.
+    pub fn reset_attempts(&mut self, id: &usize) {
+        self.attempts.remove(id);
+    }
+
+    /// Queue a single rerun of the job id, overwriting any run already
+    /// queued rather than building up a backlog.
+    pub fn queue_latest(&mut self, id: &usize) {
+        self.pending.insert(*id);
+    }
+
+    /// Take the job id's queued rerun, if any, clearing it in the process.
+    pub fn take_pending(&mut self, id: &usize) -> bool {
+        self.pending.remove(id)
     }
 }
 
+/// Callback invoked with a job's id and its panic payload (formatted as a
+/// string) whenever that job's `handle` panics.
+pub type PanicHook = Arc<dyn Fn(usize, String) + Send + Sync>;
+
 /// Runner that will hold all the jobs and will start up the execution
 /// and eventually will stop it.
 pub struct Runner {
@@ -190,6 +352,12 @@ pub struct Runner {
     pub tx: Option<UnboundedSender<Result<(), ()>>>,
     /// tracker to determine crons working
     pub working: Arc<AtomicBool>,
+    /// maximum number of jobs allowed to run at the same time, unbounded if `None`
+    pub max_concurrency: Option<usize>,
+    /// called with a job's id and panic payload whenever its `handle` panics
+    pub on_panic: Option<PanicHook>,
+    /// receives a [`JobEvent`] for every scheduling/execution event, if set via [`Runner::events`]
+    pub events: Option<UnboundedSender<JobEvent>>,
 }
 
 impl Default for Runner {
@@ -207,9 +375,51 @@ impl Runner {
             running: false,
             tx: None,
             working: Arc::new(AtomicBool::new(false)),
+            max_concurrency: None,
+            on_panic: None,
+            events: None,
         }
     }
 
+    /// Limit how many jobs are allowed to run concurrently on spawned tasks.
+    ///
+    /// Defaults to unbounded. Does nothing if already running.
+    pub fn max_concurrency(mut self, max: usize) -> Self {
+        if !self.running {
+            self.max_concurrency = Some(max);
+        }
+        self
+    }
+
+    /// Register a callback run whenever a job's `handle` panics, receiving
+    /// the job id and the panic payload formatted as a string.
+    ///
+    /// The panic is always caught and logged regardless of whether a hook is
+    /// registered; this just gives applications a chance to emit metrics or
+    /// alerts. Does nothing if already running.
+    pub fn on_panic<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize, String) + Send + Sync + 'static,
+    {
+        if !self.running {
+            self.on_panic = Some(Arc::new(callback));
+        }
+        self
+    }
+
+    /// Subscribe to the runner's job lifecycle events (start, finish,
+    /// failure, skip) by handing it the sending half of a channel you
+    /// created; keep the receiving half to read [`JobEvent`]s from.
+    ///
+    /// Useful for metrics, health checks, or driving UI updates without
+    /// instrumenting each job. Does nothing if already running.
+    pub fn events(mut self, tx: UnboundedSender<JobEvent>) -> Self {
+        if !self.running {
+            self.events = Some(tx);
+        }
+        self
+    }
+
     /// Add jobs into the runner
     ///
     /// Does nothing if already running.
@@ -233,6 +443,9 @@ impl Runner {
         }
 
         let working = Arc::new(AtomicBool::new(false));
+        let max_concurrency = self.max_concurrency;
+        let on_panic = self.on_panic.clone();
+        let events = self.events.clone();
         let (thread, tx) = spawn(self, working.clone()).await;
 
         Self {
@@ -241,6 +454,9 @@ impl Runner {
             running: true,
             tx,
             working,
+            max_concurrency,
+            on_panic,
+            events,
         }
     }
 
@@ -260,6 +476,49 @@ impl Runner {
         }
     }
 
+    /// Stop the spawned runner, and wait for the jobs currently in flight to
+    /// finish before returning instead of returning at once like
+    /// [`Runner::stop`].
+    ///
+    /// Sends the stop signal, then waits for the scheduler loop to stop
+    /// picking up new work and for every outstanding job task to complete,
+    /// up to `timeout` (or indefinitely if `None`). If the timeout elapses
+    /// first, the loop is aborted the same way [`Runner::stop`] does and the
+    /// call returns; job tasks still in flight are not aborted and keep
+    /// running in the background.
+    ///
+    /// Returns `true` if shutdown was clean, `false` if it had to be forced.
+    pub async fn stop_graceful(&mut self, timeout: Option<std::time::Duration>) -> bool {
+        if !self.running {
+            return true;
+        }
+
+        let thread = match self.thread.take() {
+            Some(thread) => thread,
+            None => return true,
+        };
+
+        if let Some(tx) = &self.tx {
+            match tx.send(Ok(())) {
+                Ok(_) => (),
+                Err(e) => error!("Could not send stop signal to cron runner thread: {}", e),
+            };
+        }
+
+        let abort_handle = thread.abort_handle();
+
+        match timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, thread).await {
+                Ok(result) => result.is_ok(),
+                Err(_) => {
+                    abort_handle.abort();
+                    false
+                }
+            },
+            None => thread.await.is_ok(),
+        }
+    }
+
     /// Lets us know if the cron worker is running
     pub fn is_running(&self) -> bool {
         self.running
@@ -271,6 +530,238 @@ impl Runner {
     }
 }
 
+/// Upper bound on how long the runner will ever sleep for in one go, so that
+/// newly reachable schedules (or a system clock jump) are re-evaluated
+/// periodically instead of only when the earliest job is due.
+const MAX_SLEEP: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Compute the next instant this job should fire at, or `None` if it has no
+/// schedule or is currently inactive.
+fn next_fire(job: &dyn Job) -> Option<DateTime<Utc>> {
+    if !job.is_active() {
+        return None;
+    }
+    job.schedule()?.upcoming(Utc).next()
+}
+
+/// Upper bound on a single retry delay, so neither a large `max_retries` nor
+/// a large `retry_backoff` can leave the runner sleeping for an absurd (or
+/// overflowing) amount of time.
+const MAX_BACKOFF: Duration = Duration::hours(1);
+
+/// Compute the delay before the given (1-based) retry attempt, as
+/// `retry_backoff * 2^(attempt - 1)`, without risking the overflow that
+/// `2i32.pow` hits for `attempt >= 32`.
+///
+/// The exponent is capped at 30 (`1i64 << 30` comfortably fits `i64`), and
+/// the multiplication is checked, so a pathologically large `max_retries` or
+/// `retry_backoff` saturates to [`MAX_BACKOFF`] instead of panicking or
+/// wrapping negative. That matters here specifically because this runs
+/// before `TRACKER.stop`, so a panic here used to leave the job wedged as
+/// permanently "running".
+fn backoff_for_attempt(retry_backoff: Duration, attempt: u32) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(30);
+    let multiplier = 1i64 << exponent;
+    let millis = retry_backoff
+        .num_milliseconds()
+        .checked_mul(multiplier)
+        .unwrap_or(i64::MAX);
+    Duration::milliseconds(millis).min(MAX_BACKOFF)
+}
+
+/// Apply up to +/-10% jitter to a retry delay, so a burst of jobs that fail
+/// at the same time don't all retry in lockstep.
+fn with_jitter(backoff: Duration) -> Duration {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let pct = (nanos % 21) as i64 - 10; // -10..=10
+    backoff + backoff * pct as i32 / 100
+}
+
+/// Best-effort rendering of a `std::panic` payload as a human-readable string.
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<&str>() {
+        Ok(message) => message.to_string(),
+        Err(payload) => match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(_) => "job panicked with a non-string payload".to_string(),
+        },
+    }
+}
+
+/// Handles of the job tasks currently in flight, so a graceful shutdown can
+/// await them instead of aborting mid-run.
+type InFlight = Arc<std::sync::Mutex<Vec<JoinHandle<()>>>>;
+
+/// Shared context threaded through every dispatch of a job, so `dispatch_job`
+/// takes a single argument instead of one per concern. All fields are cheap
+/// to clone (`Arc`/`Option<Arc>`), so the whole struct can just be cloned for
+/// the spawned task and again for a `QueueLatest` rerun.
+#[derive(Clone)]
+struct DispatchCtx {
+    working: Arc<AtomicBool>,
+    semaphore: Option<Arc<Semaphore>>,
+    retry_runs: Arc<RwLock<Vec<Option<DateTime<Utc>>>>>,
+    on_panic: Option<PanicHook>,
+    events: Option<UnboundedSender<JobEvent>>,
+    in_flight: InFlight,
+}
+
+/// Run a single due job on its own spawned task, honouring the configured
+/// concurrency limit, retry policy and panic isolation. If the job's
+/// `overlap_policy` is `QueueLatest` and a rerun was queued while this one
+/// was in flight, dispatch it again immediately once this run finishes.
+///
+/// The job is marked running in `TRACKER` synchronously, before the task is
+/// even spawned, so the overlap check the scheduler runs for the *next*
+/// trigger always sees it — even while this run is still parked waiting on
+/// the concurrency semaphore's permit.
+fn dispatch_job(id: usize, job: Arc<Mutex<Box<dyn Job>>>, ctx: DispatchCtx) {
+    if let Ok(mut s) = TRACKER.write() {
+        s.start(&id);
+    }
+
+    let in_flight = ctx.in_flight.clone();
+    let ctx_for_requeue = ctx.clone();
+
+    let handle = tokio::spawn(async move {
+        let _permit = match &ctx.semaphore {
+            Some(semaphore) => Some(
+                semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .expect("semaphore should never be closed"),
+            ),
+            None => None,
+        };
+
+        let no: String = (id + 1).to_string();
+        let (max_retries, retry_backoff) = {
+            let guard = job.lock().await;
+            (guard.max_retries(), guard.retry_backoff())
+        };
+
+        let started_at = Utc::now();
+        debug!(
+            "START: {} --- {}",
+            format!("cron-job-thread-{}", no),
+            started_at.format("%H:%M:%S%.f")
+        );
+        if let Some(tx) = &ctx.events {
+            let _ = tx.send(JobEvent::Started {
+                id,
+                at: started_at,
+            });
+        }
+
+        ctx.working.store(true, Ordering::Relaxed);
+
+        // run on its own task so a panic is caught via the JoinHandle
+        // instead of taking down the scheduler loop
+        let handle_job = job.clone();
+        let handle_result =
+            tokio::spawn(async move { handle_job.lock().await.handle().await }).await;
+
+        match handle_result {
+            Ok(Ok(())) => {
+                if let Ok(mut s) = TRACKER.write() {
+                    s.reset_attempts(&id);
+                }
+                if let Some(tx) = &ctx.events {
+                    let _ = tx.send(JobEvent::Finished {
+                        id,
+                        at: Utc::now(),
+                        duration: Utc::now() - started_at,
+                    });
+                }
+            }
+            Ok(Err(e)) => {
+                let attempt = match TRACKER.write() {
+                    Ok(mut s) => s.record_attempt(&id),
+                    Err(_) => 1,
+                };
+
+                if attempt <= max_retries {
+                    let delay = with_jitter(backoff_for_attempt(retry_backoff, attempt));
+                    if let Ok(mut r) = ctx.retry_runs.write() {
+                        r[id] = Some(Utc::now() + delay);
+                    }
+                    error!(
+                        "job {} failed on attempt {}/{}, retrying in {}ms: {}",
+                        no,
+                        attempt,
+                        max_retries,
+                        delay.num_milliseconds(),
+                        e
+                    );
+                } else {
+                    error!(
+                        "job {} failed permanently after {} attempt(s): {}",
+                        no, attempt, e
+                    );
+                    if let Ok(mut s) = TRACKER.write() {
+                        s.reset_attempts(&id);
+                    }
+                }
+                if let Some(tx) = &ctx.events {
+                    let _ = tx.send(JobEvent::Failed {
+                        id,
+                        error: e.to_string(),
+                    });
+                }
+            }
+            Err(join_err) => {
+                let payload = if join_err.is_panic() {
+                    panic_message(join_err.into_panic())
+                } else {
+                    "job task was cancelled".to_string()
+                };
+
+                error!("job {} panicked: {}", no, payload);
+
+                if let Some(hook) = &ctx.on_panic {
+                    hook(id, payload.clone());
+                }
+                if let Ok(mut s) = TRACKER.write() {
+                    s.reset_attempts(&id);
+                }
+                if let Some(tx) = &ctx.events {
+                    let _ = tx.send(JobEvent::Failed {
+                        id,
+                        error: payload,
+                    });
+                }
+            }
+        }
+
+        let (still_working, queued) = match TRACKER.write() {
+            Ok(mut s) => (s.stop(&id) != 0, s.take_pending(&id)),
+            _ => (false, false),
+        };
+        ctx.working.store(still_working, Ordering::Relaxed);
+
+        debug!(
+            "FINISH: {} --- {}",
+            format!("cron-job-thread-{}", no),
+            Utc::now().format("%H:%M:%S%.f")
+        );
+
+        if queued {
+            dispatch_job(id, job, ctx_for_requeue);
+        }
+    });
+
+    if let Ok(mut in_flight_guard) = in_flight.lock() {
+        in_flight_guard.retain(|h| !h.is_finished());
+        in_flight_guard.push(handle);
+    };
+}
+
 /// Spawn the thread for the runner and return its sender to stop it
 async fn spawn(
     runner: Runner,
@@ -284,59 +775,145 @@ async fn spawn(
         UnboundedReceiver<Result<(), ()>>,
     ) = unbounded_channel();
 
+    let jobs: Vec<Arc<Mutex<Box<dyn Job>>>> = runner
+        .jobs
+        .into_iter()
+        .map(|job| Arc::new(Mutex::new(job)))
+        .collect();
+    let semaphore = runner.max_concurrency.map(|max| Arc::new(Semaphore::new(max)));
+    let on_panic = runner.on_panic;
+    let events = runner.events;
+    // one-off retry instants, independent of each job's cron schedule
+    let retry_runs: Arc<RwLock<Vec<Option<DateTime<Utc>>>>> =
+        Arc::new(RwLock::new(vec![None; jobs.len()]));
+    let in_flight: InFlight = Arc::new(std::sync::Mutex::new(vec![]));
+    let ctx = DispatchCtx {
+        working: working.clone(),
+        semaphore,
+        retry_runs: retry_runs.clone(),
+        on_panic,
+        events,
+        in_flight: in_flight.clone(),
+    };
+
     let handler = tokio::spawn(async move {
-        let mut jobs = runner.jobs;
+        let mut next_runs: Vec<Option<DateTime<Utc>>> = {
+            let mut next_runs = Vec::with_capacity(jobs.len());
+            for job in &jobs {
+                next_runs.push(next_fire(job.lock().await.as_ref()));
+            }
+            next_runs
+        };
 
         loop {
-            if rx.try_recv().is_ok() {
-                info!("Stopping the cron runner thread");
-                break;
+            // re-check jobs that had no next fire instant (no schedule, or
+            // inactive) when they were last looked at
+            for (id, job) in jobs.iter().enumerate() {
+                if next_runs[id].is_none() {
+                    if let Ok(guard) = job.try_lock() {
+                        next_runs[id] = next_fire(guard.as_ref());
+                    }
+                }
             }
 
-            for (id, job) in jobs.iter_mut().enumerate() {
-                let no: String = (id + 1).to_string();
-
-                if job.should_run()
-                    && (job.allow_parallel_runs()
-                        || match TRACKER.read() {
-                            Ok(s) => !s.running(&id),
-                            _ => false,
-                        })
-                {
-                    match TRACKER.write() {
-                        Ok(mut s) => {
-                            s.start(&id);
-                        }
-                        _ => (),
-                    }
+            let next_retry = match retry_runs.read() {
+                Ok(r) => r.iter().flatten().min().copied(),
+                _ => None,
+            };
+            let next_due = [next_runs.iter().flatten().min().copied(), next_retry]
+                .into_iter()
+                .flatten()
+                .min();
 
-                    let now = Utc::now();
-                    debug!(
-                        "START: {} --- {}",
-                        format!("cron-job-thread-{}", no),
-                        now.format("%H:%M:%S%.f")
-                    );
+            let sleep_duration = match next_due {
+                Some(next) => (next - Utc::now())
+                    .to_std()
+                    .unwrap_or(std::time::Duration::from_secs(0)),
+                None => MAX_SLEEP,
+            }
+            .min(MAX_SLEEP);
 
-                    working.store(true, Ordering::Relaxed);
+            tokio::select! {
+                _ = tokio::time::sleep(sleep_duration) => (),
+                _ = rx.recv() => {
+                    info!("Stopping the cron runner thread");
+                    break;
+                }
+            }
 
-                    job.handle().await;
+            let now = Utc::now();
 
-                    working.store(
-                        match TRACKER.write() {
-                            Ok(mut s) => s.stop(&id) != 0,
-                            _ => false,
-                        },
-                        Ordering::Relaxed,
-                    );
+            for (id, job) in jobs.iter().enumerate() {
+                let retry_due = match retry_runs.read() {
+                    Ok(r) => matches!(r[id], Some(next) if next <= now),
+                    _ => false,
+                };
+                let is_due = retry_due || matches!(next_runs[id], Some(next) if next <= now);
+                if !is_due {
+                    continue;
+                }
 
-                    debug!(
-                        "FINISH: {} --- {}",
-                        format!("cron-job-thread-{}", no),
-                        now.format("%H:%M:%S%.f")
-                    );
+                let guard = job.lock().await;
+
+                // the next scheduled run has been consumed, compute the one after it
+                next_runs[id] = next_fire(guard.as_ref());
+                if retry_due {
+                    if let Ok(mut r) = retry_runs.write() {
+                        r[id] = None;
+                    }
                 }
+
+                let policy = guard.overlap_policy();
+                drop(guard);
+
+                let already_running = match TRACKER.read() {
+                    Ok(s) => s.running(&id),
+                    _ => false,
+                };
+
+                match policy {
+                    OverlapPolicy::Skip if already_running => {
+                        if let Some(tx) = &ctx.events {
+                            let _ = tx.send(JobEvent::Skipped {
+                                id,
+                                reason: "job already running".to_string(),
+                            });
+                        }
+                        continue;
+                    }
+                    OverlapPolicy::QueueLatest if already_running => {
+                        if let Ok(mut s) = TRACKER.write() {
+                            s.queue_latest(&id);
+                        }
+                        if let Some(tx) = &ctx.events {
+                            let _ = tx.send(JobEvent::Skipped {
+                                id,
+                                reason: "job already running, rerun queued".to_string(),
+                            });
+                        }
+                        continue;
+                    }
+                    _ => (),
+                }
+
+                dispatch_job(id, job.clone(), ctx.clone());
+            }
+        }
+
+        // don't schedule any more work; drain whatever is still in flight
+        // (and anything a QueueLatest rerun chains after it) so a graceful
+        // shutdown doesn't truncate a running job
+        loop {
+            let stragglers = match in_flight.lock() {
+                Ok(mut v) => std::mem::take(&mut *v),
+                Err(_) => vec![],
+            };
+            if stragglers.is_empty() {
+                break;
+            }
+            for straggler in stragglers {
+                let _ = straggler.await;
             }
-            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
         }
     });
 
@@ -345,10 +922,39 @@ async fn spawn(
 
 #[cfg(test)]
 mod tests {
-    use super::{Job, Runner};
+    use super::{dispatch_job, DispatchCtx, Job, JobError, JobEvent, OverlapPolicy, Runner, TRACKER};
     use async_trait::async_trait;
+    use chrono::Duration;
     use cron::Schedule;
     use std::str::FromStr;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::sync::RwLock;
+    use tokio::sync::mpsc::unbounded_channel;
+    use tokio::sync::{Mutex, Semaphore};
+
+    lazy_static::lazy_static! {
+        /// TRACKER is a process-wide singleton keyed by each job's position
+        /// within its runner, so two tests whose jobs share an id (e.g. both
+        /// the sole job in their own runner, both id 0) would otherwise race
+        /// on the same tracker entry if the test harness ran them
+        /// concurrently. Any test that actually lets a job run (rather than
+        /// just adding/stopping it) should hold this lock for its duration.
+        static ref TEST_EXECUTION_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+    }
+
+    /// Clear any tracker state a previous test left behind for the given
+    /// ids before relying on it.
+    fn reset_tracker(ids: &[usize]) {
+        if let Ok(mut tracker) = TRACKER.write() {
+            for id in ids {
+                tracker.stop(id);
+                tracker.reset_attempts(id);
+                tracker.take_pending(id);
+            }
+        }
+    }
+
     struct SomeJob;
 
     #[async_trait]
@@ -357,7 +963,9 @@ mod tests {
             Some(Schedule::from_str("0 * * * * *").unwrap())
         }
 
-        async fn handle(&mut self) {}
+        async fn handle(&mut self) -> Result<(), JobError> {
+            Ok(())
+        }
     }
     struct AnotherJob;
     #[async_trait]
@@ -366,13 +974,15 @@ mod tests {
             Some(Schedule::from_str("0 * * * * *").unwrap())
         }
 
-        async fn handle(&mut self) {}
+        async fn handle(&mut self) -> Result<(), JobError> {
+            Ok(())
+        }
     }
     #[tokio::test]
     async fn create_job() {
         let mut some_job = SomeJob;
 
-        assert_eq!(some_job.handle().await, ());
+        assert!(some_job.handle().await.is_ok());
     }
 
     #[tokio::test]
@@ -414,4 +1024,454 @@ mod tests {
 
         assert_eq!(runner.stop().await, ());
     }
+
+    #[tokio::test]
+    async fn test_stopping_the_runner_gracefully() {
+        let some_job = SomeJob;
+        let another_job = AnotherJob;
+
+        let mut runner = Runner::new()
+            .add(Box::new(some_job))
+            .add(Box::new(another_job))
+            .run()
+            .await;
+
+        assert!(runner.stop_graceful(None).await);
+    }
+
+    #[test]
+    fn test_backoff_for_attempt_does_not_overflow() {
+        use super::{backoff_for_attempt, MAX_BACKOFF};
+
+        // attempt >= 32 overflows `2i32.pow`; the capped/checked math should
+        // saturate to MAX_BACKOFF instead of panicking or wrapping negative.
+        let backoff = backoff_for_attempt(Duration::milliseconds(1), 32);
+        assert_eq!(backoff, MAX_BACKOFF);
+
+        let backoff = backoff_for_attempt(Duration::milliseconds(1), u32::MAX);
+        assert_eq!(backoff, MAX_BACKOFF);
+
+        // small attempts still double as expected
+        assert_eq!(
+            backoff_for_attempt(Duration::milliseconds(10), 1),
+            Duration::milliseconds(10)
+        );
+        assert_eq!(
+            backoff_for_attempt(Duration::milliseconds(10), 3),
+            Duration::milliseconds(40)
+        );
+    }
+
+    struct FlakyJob {
+        attempts: Arc<AtomicUsize>,
+        successes: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Job for FlakyJob {
+        fn schedule(&self) -> Option<Schedule> {
+            Some(Schedule::from_str("* * * * * *").unwrap())
+        }
+
+        fn max_retries(&self) -> u32 {
+            3
+        }
+
+        fn retry_backoff(&self) -> Duration {
+            Duration::milliseconds(10)
+        }
+
+        async fn handle(&mut self) -> Result<(), JobError> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                return Err(JobError::from("transient failure"));
+            }
+            self.successes.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_job_is_retried_and_eventually_succeeds() {
+        let _guard = TEST_EXECUTION_LOCK.lock().await;
+        reset_tracker(&[0]);
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let successes = Arc::new(AtomicUsize::new(0));
+
+        let mut runner = Runner::new()
+            .add(Box::new(FlakyJob {
+                attempts: attempts.clone(),
+                successes: successes.clone(),
+            }))
+            .run()
+            .await;
+
+        let succeeded = tokio::time::timeout(std::time::Duration::from_secs(3), async {
+            while successes.load(Ordering::SeqCst) == 0 {
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .is_ok();
+        runner.stop().await;
+
+        assert!(succeeded, "job should have succeeded after a retry");
+        assert!(attempts.load(Ordering::SeqCst) >= 2);
+    }
+
+    struct PanickyJob;
+
+    #[async_trait]
+    impl Job for PanickyJob {
+        fn schedule(&self) -> Option<Schedule> {
+            Some(Schedule::from_str("* * * * * *").unwrap())
+        }
+
+        async fn handle(&mut self) -> Result<(), JobError> {
+            panic!("job intentionally panicked for test_panicking_job_is_isolated");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_panicking_job_is_isolated_and_runner_survives() {
+        let _guard = TEST_EXECUTION_LOCK.lock().await;
+        reset_tracker(&[0, 1]);
+        let panics = Arc::new(AtomicUsize::new(0));
+        let panics_for_hook = panics.clone();
+        let (events_tx, mut events_rx) = unbounded_channel();
+
+        let mut runner = Runner::new()
+            .add(Box::new(PanickyJob))
+            .add(Box::new(SomeJob))
+            .on_panic(move |_id, _payload| {
+                panics_for_hook.fetch_add(1, Ordering::SeqCst);
+            })
+            .events(events_tx)
+            .run()
+            .await;
+
+        let saw_failed_event = tokio::time::timeout(std::time::Duration::from_secs(3), async {
+            loop {
+                if let Some(JobEvent::Failed { .. }) = events_rx.recv().await {
+                    return true;
+                }
+            }
+        })
+        .await
+        .unwrap_or(false);
+
+        assert!(saw_failed_event);
+        assert!(panics.load(Ordering::SeqCst) >= 1);
+        assert!(runner.is_running());
+
+        runner.stop().await;
+    }
+
+    struct OverlappingJob {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Job for OverlappingJob {
+        fn schedule(&self) -> Option<Schedule> {
+            Some(Schedule::from_str("* * * * * *").unwrap())
+        }
+
+        fn overlap_policy(&self) -> OverlapPolicy {
+            OverlapPolicy::QueueLatest
+        }
+
+        async fn handle(&mut self) -> Result<(), JobError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queue_latest_coalesces_overlapping_triggers() {
+        let _guard = TEST_EXECUTION_LOCK.lock().await;
+        reset_tracker(&[0]);
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let mut runner = Runner::new()
+            .add(Box::new(OverlappingJob {
+                calls: calls.clone(),
+            }))
+            .run()
+            .await;
+
+        // The schedule fires every second but each run takes 1.5s, so several
+        // triggers land while a run is in flight. QueueLatest should coalesce
+        // those into a single pending rerun rather than piling up a dispatch
+        // per tick.
+        tokio::time::sleep(std::time::Duration::from_millis(4500)).await;
+        runner.stop().await;
+
+        let n = calls.load(Ordering::SeqCst);
+        assert!(n >= 2, "expected at least one coalesced rerun, got {}", n);
+        assert!(n <= 4, "QueueLatest should not pile up duplicate runs, got {}", n);
+    }
+
+    struct SlowJob {
+        started: Arc<AtomicBool>,
+        finished: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl Job for SlowJob {
+        fn schedule(&self) -> Option<Schedule> {
+            Some(Schedule::from_str("* * * * * *").unwrap())
+        }
+
+        async fn handle(&mut self) -> Result<(), JobError> {
+            self.started.store(true, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(800)).await;
+            self.finished.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_graceful_shutdown_awaits_in_flight_job() {
+        let _guard = TEST_EXECUTION_LOCK.lock().await;
+        reset_tracker(&[0]);
+        let started = Arc::new(AtomicBool::new(false));
+        let finished = Arc::new(AtomicBool::new(false));
+
+        let mut runner = Runner::new()
+            .add(Box::new(SlowJob {
+                started: started.clone(),
+                finished: finished.clone(),
+            }))
+            .run()
+            .await;
+
+        while !started.load(Ordering::SeqCst) {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+
+        assert!(
+            runner
+                .stop_graceful(Some(std::time::Duration::from_secs(3)))
+                .await
+        );
+        assert!(
+            finished.load(Ordering::SeqCst),
+            "graceful shutdown should have awaited the in-flight job"
+        );
+    }
+
+    struct ConcurrencyProbeJob {
+        current: Arc<AtomicUsize>,
+        max_seen: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Job for ConcurrencyProbeJob {
+        fn schedule(&self) -> Option<Schedule> {
+            Some(Schedule::from_str("* * * * * *").unwrap())
+        }
+
+        async fn handle(&mut self) -> Result<(), JobError> {
+            let now = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_seen.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+            self.current.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrency_limits_in_flight_jobs() {
+        let _guard = TEST_EXECUTION_LOCK.lock().await;
+        reset_tracker(&[0, 1, 2, 3, 4]);
+        let current = Arc::new(AtomicUsize::new(0));
+        let max_seen = Arc::new(AtomicUsize::new(0));
+
+        let mut runner = Runner::new().max_concurrency(2);
+        for _ in 0..5 {
+            runner = runner.add(Box::new(ConcurrencyProbeJob {
+                current: current.clone(),
+                max_seen: max_seen.clone(),
+            }));
+        }
+        let mut runner = runner.run().await;
+
+        tokio::time::sleep(std::time::Duration::from_millis(2500)).await;
+        runner.stop().await;
+
+        let seen = max_seen.load(Ordering::SeqCst);
+        assert!(seen >= 1);
+        assert!(
+            seen <= 2,
+            "max_concurrency(2) should cap in-flight jobs, saw {}",
+            seen
+        );
+    }
+
+    struct HogJob;
+
+    #[async_trait]
+    impl Job for HogJob {
+        fn schedule(&self) -> Option<Schedule> {
+            Some(Schedule::from_str("* * * * * *").unwrap())
+        }
+
+        async fn handle(&mut self) -> Result<(), JobError> {
+            tokio::time::sleep(std::time::Duration::from_millis(3000)).await;
+            Ok(())
+        }
+    }
+
+    struct TimestampProbeJob {
+        runs: Arc<std::sync::Mutex<Vec<std::time::Instant>>>,
+        policy: OverlapPolicy,
+    }
+
+    #[async_trait]
+    impl Job for TimestampProbeJob {
+        fn schedule(&self) -> Option<Schedule> {
+            Some(Schedule::from_str("* * * * * *").unwrap())
+        }
+
+        fn overlap_policy(&self) -> OverlapPolicy {
+            self.policy
+        }
+
+        async fn handle(&mut self) -> Result<(), JobError> {
+            if let Ok(mut runs) = self.runs.lock() {
+                runs.push(std::time::Instant::now());
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_overlap_dedup_holds_while_parked_on_concurrency_permit() {
+        let _guard = TEST_EXECUTION_LOCK.lock().await;
+        reset_tracker(&[0, 1]);
+        let runs = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        // Only one permit is available, and HogJob holds it for 3s, so
+        // TimestampProbeJob (due every second, default OverlapPolicy::Skip)
+        // sits parked waiting for the permit across several scheduler ticks.
+        // If the running flag weren't set until after the permit is
+        // acquired (the bug this guards against), every one of those ticks
+        // would dispatch its own parked task, and they'd all run back-to-back
+        // the instant the permit frees up.
+        let mut runner = Runner::new()
+            .max_concurrency(1)
+            .add(Box::new(HogJob))
+            .add(Box::new(TimestampProbeJob {
+                runs: runs.clone(),
+                policy: OverlapPolicy::Skip,
+            }))
+            .run()
+            .await;
+
+        tokio::time::sleep(std::time::Duration::from_millis(3500)).await;
+        runner.stop().await;
+
+        let timestamps = runs.lock().unwrap().clone();
+        for pair in timestamps.windows(2) {
+            let gap = pair[1].duration_since(pair[0]);
+            assert!(
+                gap > std::time::Duration::from_millis(200),
+                "duplicate dispatch: probe ran twice only {:?} apart",
+                gap
+            );
+        }
+    }
+
+    struct BlockOnHandleJob;
+
+    #[async_trait]
+    impl Job for BlockOnHandleJob {
+        fn schedule(&self) -> Option<Schedule> {
+            None
+        }
+
+        fn overlap_policy(&self) -> OverlapPolicy {
+            OverlapPolicy::QueueLatest
+        }
+
+        async fn handle(&mut self) -> Result<(), JobError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_job_marks_running_before_acquiring_permit() {
+        let _guard = TEST_EXECUTION_LOCK.lock().await;
+        reset_tracker(&[0]);
+
+        // A semaphore with zero permits: the spawned task can never get
+        // past `acquire_owned`, so if the running flag were only set after
+        // that await point (the bug this guards against) it would never be
+        // set at all here. Asserting it's already set immediately after
+        // `dispatch_job` returns -- with no `.await` in between to give the
+        // runtime a chance to run the spawned task -- pins down that the
+        // mark happens synchronously in the scheduler's own stack frame.
+        // This is what lets both `Skip` and `QueueLatest` correctly treat
+        // the job as already running for every trigger that lands while it
+        // is parked waiting on a saturated concurrency permit.
+        let job: Arc<Mutex<Box<dyn Job>>> = Arc::new(Mutex::new(Box::new(BlockOnHandleJob)));
+        let ctx = DispatchCtx {
+            working: Arc::new(AtomicBool::new(false)),
+            semaphore: Some(Arc::new(Semaphore::new(0))),
+            retry_runs: Arc::new(RwLock::new(vec![None])),
+            on_panic: None,
+            events: None,
+            in_flight: Arc::new(std::sync::Mutex::new(vec![])),
+        };
+
+        dispatch_job(0, job, ctx);
+
+        assert!(TRACKER.read().unwrap().running(&0));
+    }
+
+    struct EverySecondJob;
+
+    #[async_trait]
+    impl Job for EverySecondJob {
+        fn schedule(&self) -> Option<Schedule> {
+            Some(Schedule::from_str("* * * * * *").unwrap())
+        }
+
+        async fn handle(&mut self) -> Result<(), JobError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_events_reports_started_then_finished() {
+        let _guard = TEST_EXECUTION_LOCK.lock().await;
+        reset_tracker(&[0]);
+        let (events_tx, mut events_rx) = unbounded_channel();
+
+        let mut runner = Runner::new()
+            .add(Box::new(EverySecondJob))
+            .events(events_tx)
+            .run()
+            .await;
+
+        let saw_started_then_finished =
+            tokio::time::timeout(std::time::Duration::from_secs(3), async {
+                let mut started = false;
+                loop {
+                    match events_rx.recv().await {
+                        Some(JobEvent::Started { .. }) => started = true,
+                        Some(JobEvent::Finished { .. }) if started => return true,
+                        Some(_) => (),
+                        None => return false,
+                    }
+                }
+            })
+            .await
+            .unwrap_or(false);
+
+        assert!(saw_started_then_finished);
+
+        runner.stop().await;
+    }
 }